@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use axum_core::body::Body;
 use axum_core::extract::{FromRequest, Request};
 use axum_core::response::{IntoResponse, Response};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// AWSJSON Extractor / Response.
 #[derive(Debug, Clone, Copy, Default)]
@@ -24,8 +24,81 @@ where
     }
 }
 
+impl<T> IntoResponse for AWSJson<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        match serde_json::to_vec(&self.0) {
+            Ok(bytes) => (
+                [(http::header::CONTENT_TYPE, "application/json")],
+                Body::from(bytes),
+            )
+                .into_response(),
+            Err(err) => new_service_exception(Some(err.to_string())).into_response(),
+        }
+    }
+}
+
+/// A Smithy `timestamp` shape serialized using the `epoch-seconds` trait, as required by the
+/// awsJson 1.0 and 1.1 protocols.
+///
+/// The wire representation is a JSON number of (possibly fractional) seconds since the Unix
+/// epoch, e.g. `1515531081.123`, rather than the RFC 3339 string `serde` would produce from a
+/// derived `Serialize`/`Deserialize` impl. Use this type for any field that needs that wire
+/// format instead of writing custom `#[serde(with = "...")]` helpers per handler.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Timestamp(pub f64);
+
+impl Timestamp {
+    /// Construct a `Timestamp` from a whole number of seconds since the Unix epoch.
+    #[must_use]
+    pub const fn from_secs(secs: i64) -> Self {
+        Self(secs as f64)
+    }
+
+    /// The number of (possibly fractional) seconds since the Unix epoch.
+    #[must_use]
+    pub const fn as_secs_f64(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for Timestamp {
+    fn from(seconds: f64) -> Self {
+        Self(seconds)
+    }
+}
+
+impl From<Timestamp> for f64 {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // JSON has no literal for NaN/Infinity, so `serde_json` already rejects any input that
+        // would deserialize to a non-finite `f64` before we get here; there's nothing left for
+        // this impl to validate.
+        f64::deserialize(deserializer).map(Self)
+    }
+}
+
 /// RejectionContent used for [`AWSJson`].
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RejectionContent {
     /// type of the rejection.
     #[serde(rename = "__type")]
@@ -51,13 +124,137 @@ pub struct AWSRejection {
 
 impl IntoResponse for AWSRejection {
     fn into_response(self) -> Response {
+        protocol::AwsJson11::into_response(self)
+    }
+}
+
+/// Protocol-aware rendering of [`AWSRejection`].
+///
+/// The smithy protocols axum's AWS support targets are `awsJson1_0`, `awsJson1_1`, `restJson1`
+/// and `restXml`. [`ProtocolError`] lets a router render the same [`AWSRejection`] in the shape
+/// of its configured protocol, so the error constructors below (`new_validation_exception` and
+/// friends) can stay protocol-agnostic. The three JSON impls render the same body (`__type`,
+/// `code`, `Message` and any extra fields) with an `X-Amzn-Errortype` header and differ only in
+/// `Content-Type`; the `restXml` impl renders an `<ErrorResponse>` XML document instead.
+pub mod protocol {
+    use axum_core::body::Body;
+    use axum_core::response::Response;
+
+    use super::AWSRejection;
+
+    /// A smithy protocol that knows how to render an [`AWSRejection`] on the wire.
+    pub trait ProtocolError {
+        /// Render `rejection` as a [`Response`] in this protocol's wire format.
+        fn into_response(rejection: AWSRejection) -> Response;
+    }
+
+    fn json_error_response(rejection: &AWSRejection, content_type: &'static str) -> Response {
         http::response::Response::builder()
-            .status(self.status_code)
-            .header("X-Amzn-Errortype", self.rejection.r#type.clone())
-            .body(Body::from(serde_json::to_string(&self.rejection).unwrap()))
+            .status(rejection.status_code)
+            .header(http::header::CONTENT_TYPE, content_type)
+            .header("X-Amzn-Errortype", rejection.rejection.r#type.clone())
+            .body(Body::from(serde_json::to_string(&rejection.rejection).unwrap()))
             .unwrap()
+    }
+
+    /// The `aws.protocols#awsJson1_0` protocol.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AwsJson10;
+
+    impl ProtocolError for AwsJson10 {
+        fn into_response(rejection: AWSRejection) -> Response {
+            json_error_response(&rejection, "application/x-amz-json-1.0")
+        }
+    }
+
+    /// The `aws.protocols#awsJson1_1` protocol.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AwsJson11;
+
+    impl ProtocolError for AwsJson11 {
+        fn into_response(rejection: AWSRejection) -> Response {
+            json_error_response(&rejection, "application/x-amz-json-1.1")
+        }
+    }
+
+    /// The `aws.protocols#restJson1` protocol.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AwsRestJson1;
+
+    impl ProtocolError for AwsRestJson1 {
+        fn into_response(rejection: AWSRejection) -> Response {
+            json_error_response(&rejection, "application/json")
+        }
+    }
+
+    /// The `aws.protocols#restXml` protocol.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AwsRestXml;
+
+    impl ProtocolError for AwsRestXml {
+        fn into_response(rejection: AWSRejection) -> Response {
+            // Per the smithy REST-XML error envelope, `<Type>` distinguishes a client fault
+            // (`Sender`) from a server fault (`Receiver`); derive it from the status code rather
+            // than assuming every rejection is the caller's fault.
+            let fault = if rejection.status_code.is_server_error() {
+                "Receiver"
+            } else {
+                "Sender"
+            };
+            let body = format!(
+                "<ErrorResponse><Error><Type>{fault}</Type><Code>{}</Code><Message>{}</Message></Error></ErrorResponse>",
+                xml_escape(&rejection.rejection.code),
+                xml_escape(rejection.rejection.message.as_deref().unwrap_or_default()),
+            );
+
+            http::response::Response::builder()
+                .status(rejection.status_code)
+                .header(http::header::CONTENT_TYPE, "application/xml")
+                .header("X-Amzn-Errortype", rejection.rejection.r#type.clone())
+                .body(Body::from(body))
+                .unwrap()
+        }
+    }
+
+    fn xml_escape(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+}
+
+/// If `response` carries the `X-Amzn-Errortype` header that every [`AWSRejection`] rendering path
+/// sets, treat it as a serialized [`AWSRejection`] and re-render it through `render`.
+///
+/// `AWSRejection`'s blanket [`IntoResponse`] impl always renders via [`protocol::AwsJson11`]
+/// (it has no way to know which protocol the handler that produced it is actually running
+/// under), so a rejection a handler or extractor raises directly - as opposed to one a router
+/// constructs itself, which already calls the right [`protocol::ProtocolError`] impl - comes back
+/// in the wrong wire format for any router not configured for `awsJson1_1`. Routers call this
+/// after dispatch so that case still ends up rendered in their own configured protocol.
+pub(crate) async fn reconcile_protocol(
+    response: Response,
+    render: impl FnOnce(AWSRejection) -> Response,
+) -> Response {
+    if response.headers().get("x-amzn-errortype").is_none() {
+        return response;
+    }
+
+    let status_code = response.status();
+    let (parts, body) = response.into_parts();
+    let bytes = match axum_core::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
 
-        // (self.status_code, Json(self.rejection)).into_response()
+    match serde_json::from_slice::<RejectionContent>(&bytes) {
+        Ok(rejection) => render(AWSRejection {
+            status_code,
+            rejection,
+        }),
+        Err(_) => Response::from_parts(parts, Body::from(bytes)),
     }
 }
 
@@ -134,3 +331,88 @@ pub fn new_too_many_requests_exception(
         },
     }
 }
+
+/// The request's `Content-Type` header did not match the content type the protocol requires.
+pub fn new_unsupported_media_type_exception(expected: &'static str) -> AWSRejection {
+    AWSRejection {
+        status_code: http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        rejection: RejectionContent {
+            r#type: "UnsupportedMediaTypeException".to_string(),
+            code: "UnsupportedMediaTypeException".to_string(),
+            message: Some(format!("Unsupported Media Type. Expected `{expected}`")),
+            content: HashMap::new(),
+        },
+    }
+}
+
+/// The request was missing the `Content-Type` header the protocol requires.
+pub fn new_missing_content_type_exception(expected: &'static str) -> AWSRejection {
+    AWSRejection {
+        status_code: http::StatusCode::BAD_REQUEST,
+        rejection: RejectionContent {
+            r#type: "MissingContentTypeException".to_string(),
+            code: "MissingContentTypeException".to_string(),
+            message: Some(format!("Missing Content-Type header. Expected `{expected}`")),
+            content: HashMap::new(),
+        },
+    }
+}
+
+/// The `x-amz-target` header named an operation this service does not implement.
+pub fn new_unknown_operation_exception(target: String) -> AWSRejection {
+    AWSRejection {
+        status_code: http::StatusCode::BAD_REQUEST,
+        rejection: RejectionContent {
+            r#type: "UnknownOperationException".to_string(),
+            code: "UnknownOperationException".to_string(),
+            message: Some(format!("Unknown operation `{target}`")),
+            content: HashMap::new(),
+        },
+    }
+}
+
+/// The request was missing the `x-amz-target` header the protocol requires to select an
+/// operation.
+pub fn new_missing_operation_exception() -> AWSRejection {
+    AWSRejection {
+        status_code: http::StatusCode::BAD_REQUEST,
+        rejection: RejectionContent {
+            r#type: "MissingOperationException".to_string(),
+            code: "MissingOperationException".to_string(),
+            message: Some("Missing x-amz-target header".to_string()),
+            content: HashMap::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timestamp;
+
+    #[test]
+    fn round_trips_fractional_seconds() {
+        let timestamp = Timestamp(1515531081.123);
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(json, "1515531081.123");
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+    }
+
+    #[test]
+    fn round_trips_zero_epoch() {
+        let timestamp = Timestamp(0.0);
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+    }
+
+    #[test]
+    fn round_trips_negative_epoch() {
+        let timestamp = Timestamp(-1_000_000.5);
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+    }
+
+    #[test]
+    fn rejects_non_numeric_input() {
+        assert!(serde_json::from_str::<Timestamp>("null").is_err());
+    }
+}
@@ -1,4 +1,5 @@
-//! AWS JSON router specified with aws.protocols#awsJson1_1 protocol.
+//! Routers for smithy protocols: [`AWSJsonRouter`] for aws.protocols#awsJson1_0 and
+//! aws.protocols#awsJson1_1, and [`AwsRestJson1Router`] for aws.protocols#restJson1.
 
 use core::fmt;
 use std::{
@@ -7,7 +8,7 @@ use std::{
     future::Future,
     marker::PhantomData,
     pin::Pin,
-    task::{ready, Context, Poll},
+    task::{Context, Poll},
 };
 
 use axum_core::{
@@ -16,7 +17,6 @@ use axum_core::{
     response::{IntoResponse, Response},
 };
 use http::{header::CONTENT_TYPE, HeaderValue};
-use pin_project_lite::pin_project;
 use tower::{Layer, Service};
 
 use crate::handler::Handler;
@@ -29,6 +29,42 @@ use super::{
 
 use crate::routing::IntoMakeServiceWithConnectInfo;
 
+/// The `aws.protocols#awsJson1_0`/`aws.protocols#awsJson1_1` protocol served by an
+/// [`AWSJsonRouter`].
+///
+/// The two protocols share the same dispatch mechanism (a single `POST /` endpoint selected by
+/// the `x-amz-target` header) and differ only in the `Content-Type` they require requests and
+/// responses to carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AwsJsonProtocol {
+    /// `aws.protocols#awsJson1_0`, `application/x-amz-json-1.0`.
+    Json10,
+    /// `aws.protocols#awsJson1_1`, `application/x-amz-json-1.1`.
+    Json11,
+}
+
+impl AwsJsonProtocol {
+    /// The `Content-Type` required by this protocol version.
+    const fn content_type(self) -> &'static str {
+        match self {
+            Self::Json10 => "application/x-amz-json-1.0",
+            Self::Json11 => "application/x-amz-json-1.1",
+        }
+    }
+
+    /// Render `rejection` using the [`ProtocolError`] impl for this protocol version.
+    ///
+    /// [`ProtocolError`]: crate::extract::aws_json::protocol::ProtocolError
+    fn render_rejection(self, rejection: crate::extract::aws_json::AWSRejection) -> Response {
+        use crate::extract::aws_json::protocol::{AwsJson10, AwsJson11, ProtocolError};
+
+        match self {
+            Self::Json10 => AwsJson10::into_response(rejection),
+            Self::Json11 => AwsJson11::into_response(rejection),
+        }
+    }
+}
+
 /// The router type for composing handlers and services.
 #[must_use]
 #[derive(Clone)]
@@ -37,8 +73,8 @@ pub struct AWSJsonRouter<S = ()> {
     /// The value of this header is the shape name of the service's Shape ID joined to the shape name of the operation's Shape ID,
     /// separated by a single period (.) character.
     x_amz_target: &'static str,
-    /// This header has a static value of `application/x-amz-json-1.1`.
-    content_type: &'static str,
+    /// The awsJson protocol version this router was constructed for.
+    protocol: AwsJsonProtocol,
     catch_all_fallback: Fallback<S>,
 }
 
@@ -47,7 +83,7 @@ impl<S> fmt::Debug for AWSJsonRouter<S> {
         f.debug_struct("AWSJsonRouter")
             .field("router", &self.inner)
             .field("x_amz_target", &self.x_amz_target)
-            .field("content_type", &self.content_type)
+            .field("protocol", &self.protocol)
             .field("catch_all_fallback", &self.catch_all_fallback)
             .finish()
     }
@@ -57,13 +93,23 @@ impl<S> AWSJsonRouter<S>
 where
     S: Clone + Send + Sync + 'static,
 {
-    /// Create a new router.
+    /// Create a new router serving the `aws.protocols#awsJson1_1` protocol.
     #[must_use]
     pub fn new() -> Self {
+        Self::with_protocol(AwsJsonProtocol::Json11)
+    }
+
+    /// Create a new router serving the `aws.protocols#awsJson1_0` protocol.
+    #[must_use]
+    pub fn new_json10() -> Self {
+        Self::with_protocol(AwsJsonProtocol::Json10)
+    }
+
+    fn with_protocol(protocol: AwsJsonProtocol) -> Self {
         Self {
             inner: HashMap::new(),
             x_amz_target: "x-amz-target",
-            content_type: "application/x-amz-json-1.1",
+            protocol,
             catch_all_fallback: Fallback::Default(Route::new(NotFound)),
         }
     }
@@ -74,7 +120,7 @@ where
         Self {
             inner,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: self.catch_all_fallback,
         }
     }
@@ -125,7 +171,7 @@ where
         AWSJsonRouter {
             inner: routes,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: self.catch_all_fallback.map(|route| route.layer(layer)),
         }
     }
@@ -160,7 +206,7 @@ where
         AWSJsonRouter {
             inner: routes,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: self.catch_all_fallback,
         }
     }
@@ -180,7 +226,7 @@ where
         Self {
             inner: self.inner,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: Fallback::BoxedHandler(BoxedIntoRoute::from_handler(
                 handler.clone(),
             )),
@@ -197,7 +243,7 @@ where
         Self {
             inner: self.inner,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: Fallback::Service(Route::new(service)),
         }
     }
@@ -222,7 +268,7 @@ where
         AWSJsonRouter {
             inner: routes,
             x_amz_target: self.x_amz_target,
-            content_type: self.content_type,
+            protocol: self.protocol,
             catch_all_fallback: self.catch_all_fallback.with_state(state),
         }
     }
@@ -243,37 +289,70 @@ where
         }
 
         let (parts, body) = req.into_parts();
+        let content_type = self.protocol.content_type();
+        let protocol = self.protocol;
 
-        if let Some(content_type) = parts.headers.get(http::header::CONTENT_TYPE) {
-            if content_type == self.content_type && parts.method == http::Method::POST {
-                if let Some(header_action) = parts.headers.get(self.x_amz_target) {
-                    if let Ok(action) = header_action.to_str() {
-                        if let Some(endpoint) = self.inner.get(action) {
-                            let req = Request::from_parts(parts, body);
-                            match endpoint {
-                                Endpoint::MethodRouter(method_router) => {
-                                    return AwsContentTypeFuture::new(
-                                        method_router.call_with_state(req, state),
-                                        self.content_type,
-                                    );
-                                }
-                                Endpoint::Route(route) => {
-                                    return AwsContentTypeFuture::new(
-                                        route.clone().call_owned(req),
-                                        self.content_type,
-                                    );
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        // The awsJson protocols require every request to hit the root URL via POST; anything
+        // else isn't an awsJson request at all, so it falls through to the catch-all fallback
+        // rather than being rejected as a malformed awsJson request.
+        if parts.uri.path() != "/" || parts.method != http::Method::POST {
+            let req = Request::from_parts(parts, body);
+            return AwsContentTypeFuture::new(
+                self.catch_all_fallback.clone().call_with_state(req, state),
+                content_type,
+                move |rejection| protocol.render_rejection(rejection),
+            );
+        }
+
+        let rejection = match parts.headers.get(http::header::CONTENT_TYPE) {
+            None => Some(crate::extract::aws_json::new_missing_content_type_exception(
+                content_type,
+            )),
+            Some(request_content_type) if request_content_type != content_type => Some(
+                crate::extract::aws_json::new_unsupported_media_type_exception(
+                    content_type,
+                ),
+            ),
+            Some(_) => match parts
+                .headers
+                .get(self.x_amz_target)
+                .and_then(|value| value.to_str().ok())
+            {
+                Some(action) if self.inner.contains_key(action) => None,
+                Some(action) => Some(crate::extract::aws_json::new_unknown_operation_exception(
+                    action.to_string(),
+                )),
+                None => Some(crate::extract::aws_json::new_missing_operation_exception()),
+            },
+        };
+
+        if let Some(rejection) = rejection {
+            return AwsContentTypeFuture::new(
+                RouteFuture::from_response(protocol.render_rejection(rejection)),
+                content_type,
+                move |rejection| protocol.render_rejection(rejection),
+            );
+        }
+
+        let action = parts
+            .headers
+            .get(self.x_amz_target)
+            .and_then(|value| value.to_str().ok())
+            .expect("checked above");
+        let endpoint = self.inner.get(action).expect("checked above");
+        let req = Request::from_parts(parts, body);
+        match endpoint {
+            Endpoint::MethodRouter(method_router) => AwsContentTypeFuture::new(
+                method_router.call_with_state(req, state),
+                content_type,
+                move |rejection| protocol.render_rejection(rejection),
+            ),
+            Endpoint::Route(route) => AwsContentTypeFuture::new(
+                route.clone().call_owned(req),
+                content_type,
+                move |rejection| protocol.render_rejection(rejection),
+            ),
         }
-        let (req, state) = (Request::from_parts(parts, body), state); // invalid input
-        AwsContentTypeFuture::new(
-            self.catch_all_fallback.clone().call_with_state(req, state),
-            self.content_type,
-        )
     }
 
     /// Convert the router into an owned [`Service`] with a fixed request body type, to aid type
@@ -415,33 +494,576 @@ where
     }
 }
 
-pin_project! {
-    pub struct AwsContentTypeFuture<E> {
-        #[pin]
-        future: RouteFuture<E>,
-        content_type: &'static str,    }
+/// The future returned by [`AWSJsonRouter::call_with_state`] and
+/// [`AwsRestJson1Router::call_with_state`].
+///
+/// Stamps the router's configured `Content-Type` onto the response and, if the response is an
+/// [`AWSRejection`](crate::extract::aws_json::AWSRejection) rendered by something other than the
+/// router itself (e.g. a handler returning `Err(new_validation_exception(...))`), re-renders it
+/// through the router's protocol first; see
+/// [`reconcile_protocol`](crate::extract::aws_json::reconcile_protocol).
+pub struct AwsContentTypeFuture<E> {
+    inner: Pin<Box<dyn Future<Output = Result<Response, E>> + Send>>,
 }
 
-impl<E> AwsContentTypeFuture<E> {
-    fn new(future: RouteFuture<E>, content_type: &'static str) -> Self {
+impl<E> AwsContentTypeFuture<E>
+where
+    E: Send + 'static,
+{
+    fn new<F>(future: RouteFuture<E>, content_type: &'static str, render_rejection: F) -> Self
+    where
+        F: FnOnce(crate::extract::aws_json::AWSRejection) -> Response + Send + 'static,
+    {
         Self {
-            future,
-            content_type,
+            inner: Box::pin(async move {
+                let response = future.await?;
+                let mut response =
+                    crate::extract::aws_json::reconcile_protocol(response, render_rejection).await;
+
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+
+                Ok(response)
+            }),
         }
     }
 }
 
 impl<E> Future for AwsContentTypeFuture<E> {
-    type Output = <RouteFuture<E> as Future>::Output;
+    type Output = Result<Response, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+/// The REST protocol served by an [`AwsRestJson1Router`].
+///
+/// Both protocols share the same method + URI-template dispatch mechanism and differ only in the
+/// `Content-Type` stamped on every response and in how rejections are rendered; handler response
+/// bodies are passed through as the handler produced them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AwsRestProtocol {
+    /// `aws.protocols#restJson1`, `application/json` bodies.
+    RestJson1,
+    /// `aws.protocols#restXml`, `application/xml` bodies.
+    RestXml,
+}
+
+impl AwsRestProtocol {
+    /// The `Content-Type` this protocol stamps on responses.
+    const fn content_type(self) -> &'static str {
+        match self {
+            Self::RestJson1 => "application/json",
+            Self::RestXml => "application/xml",
+        }
+    }
+
+    /// Render `rejection` using the [`ProtocolError`] impl for this protocol.
+    ///
+    /// [`ProtocolError`]: crate::extract::aws_json::protocol::ProtocolError
+    fn render_rejection(self, rejection: crate::extract::aws_json::AWSRejection) -> Response {
+        use crate::extract::aws_json::protocol::{AwsRestJson1, AwsRestXml, ProtocolError};
+
+        match self {
+            Self::RestJson1 => AwsRestJson1::into_response(rejection),
+            Self::RestXml => AwsRestXml::into_response(rejection),
+        }
+    }
+
+    /// The rejection emitted when no route matches a request's method and path.
+    fn not_found_rejection(self) -> crate::extract::aws_json::AWSRejection {
+        crate::extract::aws_json::AWSRejection {
+            status_code: http::StatusCode::NOT_FOUND,
+            rejection: crate::extract::aws_json::RejectionContent {
+                r#type: "NotFoundException".to_string(),
+                code: "NotFoundException".to_string(),
+                message: Some("No route matched the request method and path".to_string()),
+                content: HashMap::new(),
+            },
+        }
+    }
+}
+
+/// One segment of a `AwsRestJson1Router` URI template, e.g. `users` or `{id}` in `/users/{id}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    /// A literal path segment that must match verbatim.
+    Static(&'static str),
+    /// A `{label}` path segment whose matched value is captured under `label`.
+    Label(&'static str),
+}
+
+fn parse_template(template: &'static str) -> Vec<PathSegment> {
+    template
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(label) => PathSegment::Label(label),
+            None => PathSegment::Static(segment),
+        })
+        .collect()
+}
+
+/// Panic if `segments` is already registered in `routes`.
+///
+/// Two `.route()` calls for the same URI template silently shadow one another instead of
+/// combining into a single [`MethodRouter`] (the routed-to `MethodRouter` only knows the methods
+/// it was built with), so treat it the same way the crate's normal `Router` treats duplicate
+/// paths: panic at registration time.
+#[track_caller]
+fn panic_on_duplicate_route<S>(
+    routes: &[(Vec<PathSegment>, Endpoint<S>)],
+    segments: &[PathSegment],
+    uri_template: &'static str,
+) {
+    if routes.iter().any(|(existing, _)| existing == segments) {
+        panic!(
+            "Overlapping method route: `{uri_template}` was already registered. Combine the \
+             handlers for the same path into one `MethodRouter` (e.g. \
+             `get(a).post(b)`) instead of calling `route` for the same path twice."
+        );
+    }
+}
+
+/// The number of `{label}` segments in a template; lower is more specific.
+///
+/// Used to prefer a static route (e.g. `/users/me`) over an overlapping labelled one
+/// (e.g. `/users/{id}`) regardless of which was registered first.
+fn label_count(segments: &[PathSegment]) -> usize {
+    segments
+        .iter()
+        .filter(|segment| matches!(segment, PathSegment::Label(_)))
+        .count()
+}
+
+fn match_path(segments: &[PathSegment], path: &str) -> Option<HashMap<String, String>> {
+    let path_segments: Vec<&str> = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if path_segments.len() != segments.len() {
+        return None;
+    }
+
+    let mut params = HashMap::new();
+    for (segment, path_segment) in segments.iter().zip(path_segments.iter()) {
+        match segment {
+            PathSegment::Static(expected) => {
+                if expected != path_segment {
+                    return None;
+                }
+            }
+            PathSegment::Label(label) => {
+                params.insert((*label).to_string(), (*path_segment).to_string());
+            }
+        }
+    }
+    Some(params)
+}
+
+/// Path labels captured by [`AwsRestJson1Router`] from a matched `{label}` URI template segment.
+///
+/// Stored as a request extension; read it with `Extension<PathParams>` the same way you would
+/// read any other value this crate's middleware inserts into the request.
+#[derive(Debug, Clone, Default)]
+pub struct PathParams(pub HashMap<String, String>);
+
+/// A router that dispatches on HTTP method and URI template, as used by the
+/// `aws.protocols#restJson1` protocol.
+///
+/// Unlike [`AWSJsonRouter`], which dispatches purely on the `x-amz-target` header, this router
+/// matches `{label}` path segments (e.g. `/users/{id}`) the way smithy-rs's REST protocols do,
+/// capturing them into a [`PathParams`] request extension. Method dispatch (including an
+/// automatic `405`) is handled by the routed-to [`MethodRouter`], and routes otherwise share the
+/// same [`Endpoint`], [`Fallback`], [`Route`], and layer/`with_state` plumbing as
+/// [`AWSJsonRouter`].
+#[must_use]
+#[derive(Clone)]
+pub struct AwsRestJson1Router<S = ()> {
+    routes: Vec<(Vec<PathSegment>, Endpoint<S>)>,
+    protocol: AwsRestProtocol,
+    catch_all_fallback: Fallback<S>,
+}
+
+impl<S> fmt::Debug for AwsRestJson1Router<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AwsRestJson1Router")
+            .field("routes", &self.routes)
+            .field("protocol", &self.protocol)
+            .field("catch_all_fallback", &self.catch_all_fallback)
+            .finish()
+    }
+}
+
+impl<S> AwsRestJson1Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Create a new router serving the `aws.protocols#restJson1` protocol.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_protocol(AwsRestProtocol::RestJson1)
+    }
+
+    /// Create a new router serving the `aws.protocols#restXml` protocol.
+    #[must_use]
+    pub fn new_rest_xml() -> Self {
+        Self::with_protocol(AwsRestProtocol::RestXml)
+    }
+
+    fn with_protocol(protocol: AwsRestProtocol) -> Self {
+        Self {
+            routes: Vec::new(),
+            protocol,
+            catch_all_fallback: Fallback::Default(Route::new(tower::service_fn(
+                move |_req: Request| {
+                    let response = protocol.render_rejection(protocol.not_found_rejection());
+                    std::future::ready(Ok::<_, Infallible>(response))
+                },
+            ))),
+        }
+    }
+
+    /// Add another route to the router, matching `uri_template` (e.g. `/users/{id}`) under any
+    /// method accepted by `method_router`.
+    #[track_caller]
+    pub fn route(mut self, uri_template: &'static str, method_router: MethodRouter<S>) -> Self {
+        let segments = parse_template(uri_template);
+        panic_on_duplicate_route(&self.routes, &segments, uri_template);
+        self.routes.push((segments, Endpoint::MethodRouter(method_router)));
+        self
+    }
+
+    /// Add another route to the router that calls a [`Service`].
+    pub fn route_service<T>(mut self, uri_template: &'static str, service: T) -> Self
+    where
+        T: Service<Request, Error = Infallible> + Clone + Send + Sync + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        let service = match try_downcast::<AwsRestJson1Router<S>, _>(service) {
+            Ok(_) => {
+                panic!(
+                    "Invalid route: `AwsRestJson1Router::route_service` cannot be used with `AwsRestJson1Router`s."
+                );
+            }
+            Err(service) => service,
+        };
+
+        let segments = parse_template(uri_template);
+        panic_on_duplicate_route(&self.routes, &segments, uri_template);
+        self.routes.push((segments, Endpoint::Route(Route::new(service))));
+        self
+    }
+
+    /// Apply a [`tower::Layer`] to all routes in the router.
+    pub fn layer<L>(self, layer: L) -> AwsRestJson1Router<S>
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        let routes = self
+            .routes
+            .into_iter()
+            .map(|(segments, endpoint)| (segments, endpoint.layer(layer.clone())))
+            .collect();
+
+        AwsRestJson1Router {
+            routes,
+            protocol: self.protocol,
+            catch_all_fallback: self.catch_all_fallback.map(|route| route.layer(layer)),
+        }
+    }
+
+    /// Apply a [`tower::Layer`] to the router that will only run if the request matches
+    /// a route.
+    #[track_caller]
+    pub fn route_layer<L>(self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        if self.routes.is_empty() {
+            panic!(
+                "Adding a route_layer before any routes is a no-op. \
+             Add the routes you want the layer to apply to first."
+            );
+        }
+
+        let routes = self
+            .routes
+            .into_iter()
+            .map(|(segments, endpoint)| (segments, endpoint.layer(layer.clone())))
+            .collect();
+
+        AwsRestJson1Router {
+            routes,
+            protocol: self.protocol,
+            catch_all_fallback: self.catch_all_fallback,
+        }
+    }
+
+    /// True if the router currently has at least one route added.
+    pub fn has_routes(&self) -> bool {
+        !self.routes.is_empty()
+    }
+
+    /// Add a fallback [`Handler`] to the router.
+    #[track_caller]
+    pub fn fallback<H, T>(self, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: 'static,
+    {
+        Self {
+            routes: self.routes,
+            protocol: self.protocol,
+            catch_all_fallback: Fallback::BoxedHandler(BoxedIntoRoute::from_handler(
+                handler.clone(),
+            )),
+        }
+    }
+
+    /// Add a fallback [`Service`] to the router.
+    pub fn fallback_service<T>(self, service: T) -> Self
+    where
+        T: Service<Request, Error = Infallible> + Clone + Send + Sync + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        Self {
+            routes: self.routes,
+            protocol: self.protocol,
+            catch_all_fallback: Fallback::Service(Route::new(service)),
+        }
+    }
+
+    /// Provide the state for the router. State passed to this method is global and will be used
+    /// for all requests this router receives. That means it is not suitable for holding state
+    /// derived from a request, such as authorization data extracted in a middleware. Use
+    /// [`Extension`] instead for such data.
+    pub fn with_state<S2>(self, state: S) -> AwsRestJson1Router<S2> {
+        let routes = self
+            .routes
+            .into_iter()
+            .map(|(segments, endpoint)| {
+                let endpoint: Endpoint<S2> = match endpoint {
+                    Endpoint::MethodRouter(method_router) => {
+                        Endpoint::MethodRouter(method_router.with_state(state.clone()))
+                    }
+                    Endpoint::Route(route) => Endpoint::Route(route),
+                };
+                (segments, endpoint)
+            })
+            .collect();
+
+        AwsRestJson1Router {
+            routes,
+            protocol: self.protocol,
+            catch_all_fallback: self.catch_all_fallback.with_state(state),
+        }
+    }
+
+    pub(crate) fn call_with_state(
+        &self,
+        mut req: Request,
+        state: S,
+    ) -> AwsContentTypeFuture<Infallible> {
+        #[cfg(feature = "original-uri")]
+        {
+            use crate::extract::OriginalUri;
+
+            if req.extensions().get::<OriginalUri>().is_none() {
+                let original_uri = OriginalUri(req.uri().clone());
+                req.extensions_mut().insert(original_uri);
+            }
+        }
+
+        let path = req.uri().path().to_owned();
+        let protocol = self.protocol;
+
+        // Find every template whose segment count and static segments match, then dispatch to
+        // the most specific one (fewest `{label}`s) rather than the first one registered, so a
+        // static route like `/users/me` wins over an overlapping `/users/{id}` irrespective of
+        // registration order.
+        let best = self
+            .routes
+            .iter()
+            .filter_map(|(segments, endpoint)| {
+                match_path(segments, &path).map(|params| (label_count(segments), endpoint, params))
+            })
+            .min_by_key(|(specificity, ..)| *specificity);
+
+        if let Some((_, endpoint, params)) = best {
+            req.extensions_mut().insert(PathParams(params));
+            return match endpoint {
+                Endpoint::MethodRouter(method_router) => AwsContentTypeFuture::new(
+                    method_router.call_with_state(req, state),
+                    protocol.content_type(),
+                    move |rejection| protocol.render_rejection(rejection),
+                ),
+                Endpoint::Route(route) => AwsContentTypeFuture::new(
+                    route.clone().call_owned(req),
+                    protocol.content_type(),
+                    move |rejection| protocol.render_rejection(rejection),
+                ),
+            };
+        }
+
+        AwsContentTypeFuture::new(
+            self.catch_all_fallback.clone().call_with_state(req, state),
+            protocol.content_type(),
+            move |rejection| protocol.render_rejection(rejection),
+        )
+    }
+
+    /// Convert the router into an owned [`Service`] with a fixed request body type, to aid type
+    /// inference.
+    pub fn into_service<B>(self) -> AwsRestJson1RouterIntoService<B, S> {
+        AwsRestJson1RouterIntoService {
+            router: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl AwsRestJson1Router {
+    /// Convert this router into a [`MakeService`], that is a [`Service`] whose
+    /// response is another service.
+    /// [`MakeService`]: tower::make::MakeService
+    pub fn into_make_service(self) -> IntoMakeService<Self> {
+        // call `AwsRestJson1Router::with_state` such that everything is turned into `Route`
+        // eagerly rather than doing that per request
+        IntoMakeService::new(self.with_state(()))
+    }
+
+    /// Convert this router into a [`MakeService`], that will store `C`'s
+    /// associated `ConnectInfo` in a request extension such that [`ConnectInfo`]
+    /// can extract it.
+    ///
+    /// This enables extracting things like the client's remote address.
+    ///
+    /// Extracting [`std::net::SocketAddr`] is supported out of the box.
+    #[cfg(feature = "tokio")]
+    pub fn into_make_service_with_connect_info<C>(self) -> IntoMakeServiceWithConnectInfo<Self, C> {
+        // call `AwsRestJson1Router::with_state` such that everything is turned into `Route`
+        // eagerly rather than doing that per request
+
+        use crate::extract::connect_info::IntoMakeServiceWithConnectInfo;
+        IntoMakeServiceWithConnectInfo::new(self.with_state(()))
+    }
+}
+
+impl<B> Service<Request<B>> for AwsRestJson1Router<()>
+where
+    B: HttpBody<Data = bytes::Bytes> + Send + 'static,
+    B::Error: Into<axum_core::BoxError>,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = AwsContentTypeFuture<Infallible>;
+
+    #[inline]
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let req = req.map(Body::new);
+        self.call_with_state(req, ())
+    }
+}
+
+// for `axum::serve(listener, router)`
+#[cfg(all(feature = "tokio", any(feature = "http1", feature = "http2")))]
+const _: () = {
+    use crate::serve;
+
+    impl<L> Service<serve::IncomingStream<'_, L>> for AwsRestJson1Router<()>
+    where
+        L: serve::Listener,
+    {
+        type Response = Self;
+        type Error = Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.project();
-        let mut response = ready!(this.future.poll(cx)?);
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
 
-        response
-            .headers_mut()
-            .insert(CONTENT_TYPE, HeaderValue::from_static(this.content_type));
+        fn call(&mut self, _req: serve::IncomingStream<'_, L>) -> Self::Future {
+            // call `AwsRestJson1Router::with_state` such that everything is turned into `Route`
+            // eagerly rather than doing that per request
+            std::future::ready(Ok(self.clone().with_state(())))
+        }
+    }
+};
 
-        Poll::Ready(Ok(response))
+impl Default for AwsRestJson1Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`AwsRestJson1Router`] converted into an owned [`Service`] with a fixed body type.
+///
+/// See [`AwsRestJson1Router::into_service`] for more details.
+pub struct AwsRestJson1RouterIntoService<B, S = ()> {
+    router: AwsRestJson1Router<S>,
+    _marker: PhantomData<B>,
+}
+
+impl<B, S> Clone for AwsRestJson1RouterIntoService<B, S>
+where
+    AwsRestJson1Router<S>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            router: self.router.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<B, S> fmt::Debug for AwsRestJson1RouterIntoService<B, S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AwsRestJson1RouterIntoService")
+            .field("router", &self.router)
+            .finish()
+    }
+}
+
+impl<B> Service<Request<B>> for AwsRestJson1RouterIntoService<B, ()>
+where
+    B: HttpBody<Data = bytes::Bytes> + Send + 'static,
+    B::Error: Into<axum_core::BoxError>,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = AwsContentTypeFuture<Infallible>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        <AwsRestJson1Router as Service<Request<B>>>::poll_ready(&mut self.router, cx)
+    }
+
+    #[inline]
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        self.router.call(req)
     }
 }
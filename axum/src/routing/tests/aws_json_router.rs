@@ -1,4 +1,8 @@
-use crate::{routing::get, test_helpers::*, AWSJsonRouter};
+use crate::{
+    routing::{delete, get, post},
+    test_helpers::*,
+    AWSJsonRouter,
+};
 use axum_core::extract::Request;
 
 use http::StatusCode;
@@ -22,3 +26,218 @@ async fn routing() {
     println!("{:?}", res);
     assert_eq!(res.status(), StatusCode::NOT_FOUND);
 }
+
+#[crate::test]
+async fn json10_stamps_the_1_0_content_type() {
+    let app = AWSJsonRouter::new_json10().route("Service.Operation", post(|_: Request| async { "ok" }));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("content-type", "application/x-amz-json-1.0")
+        .header("x-amz-target", "Service.Operation")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/x-amz-json-1.0",
+    );
+}
+
+#[crate::test]
+async fn json10_rejects_the_1_1_content_type() {
+    let app = AWSJsonRouter::new_json10().route("Service.Operation", post(|_: Request| async { "ok" }));
+
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("content-type", "application/x-amz-json-1.1")
+        .header("x-amz-target", "Service.Operation")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "application/x-amz-json-1.0",
+    );
+}
+
+// The REST `ProtocolError` impls are exercised end to end through
+// `AwsRestJson1Router`'s default not-found fallback, which is the one rejection every
+// `AwsRestJson1Router` can produce without any routes of its own: one router per protocol
+// should render the same `AWSRejection` into its own wire format.
+#[crate::test]
+async fn rest_json1_renders_rejections_as_json() {
+    use crate::routing::aws_json_router::AwsRestJson1Router;
+
+    let app = AwsRestJson1Router::<()>::new().route("/users", get(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client.get("/missing").await;
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.headers().get("content-type").unwrap(), "application/json");
+    assert!(res.text().await.contains("\"__type\":\"NotFoundException\""));
+}
+
+#[crate::test]
+async fn rest_xml_renders_rejections_as_xml() {
+    use crate::routing::aws_json_router::AwsRestJson1Router;
+
+    let app =
+        AwsRestJson1Router::<()>::new_rest_xml().route("/users", get(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client.get("/missing").await;
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.headers().get("content-type").unwrap(), "application/xml");
+    let body = res.text().await;
+    assert!(body.starts_with("<ErrorResponse>"));
+    assert!(body.contains("<Code>NotFoundException</Code>"));
+}
+
+#[crate::test]
+async fn missing_content_type_is_rejected() {
+    let app = AWSJsonRouter::new().route("Service.Operation", post(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("x-amz-target", "Service.Operation")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert!(res
+        .text()
+        .await
+        .contains("\"__type\":\"MissingContentTypeException\""));
+}
+
+#[crate::test]
+async fn wrong_content_type_is_rejected() {
+    let app = AWSJsonRouter::new().route("Service.Operation", post(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("content-type", "application/json")
+        .header("x-amz-target", "Service.Operation")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(res
+        .text()
+        .await
+        .contains("\"__type\":\"UnsupportedMediaTypeException\""));
+}
+
+#[crate::test]
+async fn missing_target_header_is_rejected() {
+    let app = AWSJsonRouter::new().route("Service.Operation", post(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("content-type", "application/x-amz-json-1.1")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert!(res
+        .text()
+        .await
+        .contains("\"__type\":\"MissingOperationException\""));
+}
+
+#[crate::test]
+async fn unknown_target_is_rejected() {
+    let app = AWSJsonRouter::new().route("Service.Operation", post(|_: Request| async { "ok" }));
+    let client = TestClient::new(app);
+
+    let res = client
+        .post("/")
+        .header("content-type", "application/x-amz-json-1.1")
+        .header("x-amz-target", "Service.DoesNotExist")
+        .body("{}")
+        .await;
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert!(res
+        .text()
+        .await
+        .contains("\"__type\":\"UnknownOperationException\""));
+}
+
+#[crate::test]
+async fn rest_json1_captures_path_labels() {
+    use crate::routing::aws_json_router::{AwsRestJson1Router, PathParams};
+
+    let app = AwsRestJson1Router::<()>::new().route(
+        "/users/{id}",
+        get(|req: Request| async move {
+            req.extensions()
+                .get::<PathParams>()
+                .unwrap()
+                .0
+                .get("id")
+                .unwrap()
+                .clone()
+        }),
+    );
+    let client = TestClient::new(app);
+
+    let res = client.get("/users/42").await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.text().await, "42");
+}
+
+#[crate::test]
+async fn rest_json1_prefers_static_segments_over_labels() {
+    use crate::routing::aws_json_router::AwsRestJson1Router;
+
+    // `/users/me` is registered after the overlapping `/users/{id}`, but the static route
+    // should still win: registration order must not decide which template matches.
+    let app = AwsRestJson1Router::<()>::new()
+        .route("/users/{id}", get(|_: Request| async { "users#show" }))
+        .route("/users/me", get(|_: Request| async { "users#me" }));
+    let client = TestClient::new(app);
+
+    let res = client.get("/users/me").await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.text().await, "users#me");
+}
+
+#[test]
+#[should_panic(expected = "already registered")]
+fn rest_json1_panics_on_duplicate_route() {
+    use crate::routing::aws_json_router::AwsRestJson1Router;
+
+    let _ = AwsRestJson1Router::<()>::new()
+        .route("/users/{id}", get(|_: Request| async { "a" }))
+        .route("/users/{id}", delete(|_: Request| async { "b" }));
+}
+
+#[crate::test]
+async fn rest_json1_falls_back_when_no_template_matches() {
+    use crate::routing::aws_json_router::AwsRestJson1Router;
+
+    let app = AwsRestJson1Router::<()>::new()
+        .route("/users", get(|_: Request| async { "users#index" }))
+        .fallback(|| async { (StatusCode::IM_A_TEAPOT, "nope") });
+    let client = TestClient::new(app);
+
+    let res = client.get("/not-a-route").await;
+
+    assert_eq!(res.status(), StatusCode::IM_A_TEAPOT);
+    assert_eq!(res.text().await, "nope");
+}